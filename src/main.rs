@@ -5,15 +5,41 @@ struct Arguments {
     filename: String,
     output: String,
     case_insensitive: bool,
+    multiline: bool,
+    dotall: bool,
+    ignore_whitespace: bool,
+    word: bool,
+    literal: bool,
+    no_expand: bool,
+    in_place: bool,
+    backup: bool,
     interactive: bool,
     preview: bool,
     log_file: Option<String>,
+    log_format: String,
+}
+
+impl Arguments {
+    /// Collect the regex behavior flags into the struct `replace` expects.
+    fn replace_options(&self) -> ReplaceOptions {
+        ReplaceOptions {
+            case_insensitive: self.case_insensitive,
+            multiline: self.multiline,
+            dotall: self.dotall,
+            ignore_whitespace: self.ignore_whitespace,
+            word: self.word,
+            literal: self.literal,
+            no_expand: self.no_expand,
+        }
+    }
 }
 
 use text_colorizer::*;
 use std::{env, fs};
-use regex::Regex;
-use std::io::{self, Write};
+use regex::{Regex, RegexBuilder};
+use serde::Serialize;
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
 
 fn print_usage() {
     eprintln!(
@@ -21,27 +47,102 @@ fn print_usage() {
         "QuickReplace".green().bold()
     );
     eprintln!("{}:", "Usage".blue().bold());
-    eprintln!("  quickreplace <target> <replacement> <INPUT> <OUTPUT> [options]");
+    eprintln!("  quickreplace <target> <replacement> <INPUT> [OUTPUT] [options]");
+    eprintln!("  (use '-' as <INPUT> or <OUTPUT> to stream via stdin/stdout)");
+    eprintln!("  (<INPUT> may be a glob like 'src/**/*.txt'; <OUTPUT> may be a directory");
+    eprintln!("   or omitted with --in-place to edit matched files where they live)");
     eprintln!("\n{}:", "Options".blue().bold());
     eprintln!("  --case-insensitive      Perform case-insensitive replacement");
+    eprintln!("  --multiline             Make ^/$ match at line boundaries");
+    eprintln!("  --dotall                Make '.' match newlines");
+    eprintln!("  --word                  Match <target> only as a whole word (\\b...\\b)");
+    eprintln!("  --flags <imsx>          Shorthand: i=case, m=multiline, s=dotall, x=ignore-ws");
+    eprintln!("  --literal               Match <target> verbatim (escape regex metacharacters)");
+    eprintln!("  --no-expand             Do not expand $1/${{name}} references in <replacement>");
+    eprintln!("  --in-place              Edit matched files in place (no <OUTPUT> needed)");
+    eprintln!("  --backup                With --in-place, write a <file>.bak copy first");
     eprintln!("  --interactive           Review each replacement interactively");
     eprintln!("  --preview               Show a preview of the changes before saving");
     eprintln!("  --log-file <FILE>       Save a log of changes to the specified file");
+    eprintln!("  --log-format <FMT>      Log format: 'text' (default) or 'json'");
     eprintln!();
 }
 
 fn parse_args() -> Arguments {
     let mut args: Vec<String> = env::args().skip(1).collect();
     let mut case_insensitive = false;
+    let mut multiline = false;
+    let mut dotall = false;
+    let mut ignore_whitespace = false;
+    let mut word = false;
+    let mut literal = false;
+    let mut no_expand = false;
+    let mut in_place = false;
+    let mut backup = false;
     let mut interactive = false;
     let mut preview = false;
     let mut log_file = None;
+    let mut log_format = String::from("text");
 
     // Parse options
     if let Some(index) = args.iter().position(|x| x == "--case-insensitive") {
         case_insensitive = true;
         args.remove(index);
     }
+    if let Some(index) = args.iter().position(|x| x == "--multiline") {
+        multiline = true;
+        args.remove(index);
+    }
+    if let Some(index) = args.iter().position(|x| x == "--dotall") {
+        dotall = true;
+        args.remove(index);
+    }
+    if let Some(index) = args.iter().position(|x| x == "--word") {
+        word = true;
+        args.remove(index);
+    }
+    if let Some(index) = args.iter().position(|x| x == "--flags") {
+        if index + 1 < args.len() {
+            let flags = args[index + 1].clone();
+            args.drain(index..=index + 1);
+            for c in flags.chars() {
+                match c {
+                    'i' => case_insensitive = true,
+                    'm' => multiline = true,
+                    's' => dotall = true,
+                    'x' => ignore_whitespace = true,
+                    other => {
+                        eprintln!(
+                            "{} Unknown flag '{}' in --flags; expected any of 'imsx'.\n",
+                            "Error:".red().bold(),
+                            other
+                        );
+                        std::process::exit(1);
+                    }
+                }
+            }
+        } else {
+            eprintln!("{} Missing value after --flags\n", "Error:".red().bold());
+            print_usage();
+            std::process::exit(1);
+        }
+    }
+    if let Some(index) = args.iter().position(|x| x == "--literal") {
+        literal = true;
+        args.remove(index);
+    }
+    if let Some(index) = args.iter().position(|x| x == "--no-expand") {
+        no_expand = true;
+        args.remove(index);
+    }
+    if let Some(index) = args.iter().position(|x| x == "--in-place") {
+        in_place = true;
+        args.remove(index);
+    }
+    if let Some(index) = args.iter().position(|x| x == "--backup") {
+        backup = true;
+        args.remove(index);
+    }
     if let Some(index) = args.iter().position(|x| x == "--interactive") {
         interactive = true;
         args.remove(index);
@@ -60,11 +161,41 @@ fn parse_args() -> Arguments {
             std::process::exit(1);
         }
     }
+    if let Some(index) = args.iter().position(|x| x == "--log-format") {
+        if index + 1 < args.len() {
+            log_format = args[index + 1].clone();
+            args.drain(index..=index + 1);
+        } else {
+            eprintln!("{} Missing value after --log-format\n", "Error:".red().bold());
+            print_usage();
+            std::process::exit(1);
+        }
+        if log_format != "text" && log_format != "json" {
+            eprintln!(
+                "{} Unknown log format '{}'; expected 'text' or 'json'.\n",
+                "Error:".red().bold(),
+                log_format
+            );
+            std::process::exit(1);
+        }
+    }
 
-    if args.len() != 4 {
+    // <OUTPUT> may be omitted only when --in-place is explicitly requested, so
+    // a forgotten output path is still a hard error rather than a silent
+    // in-place clobber of the original.
+    if args.len() == 3 {
+        if !in_place {
+            print_usage();
+            eprintln!(
+                "{} Missing <OUTPUT>. Pass an output path, or --in-place to edit the input.\n",
+                "Error:".red().bold()
+            );
+            std::process::exit(1);
+        }
+    } else if args.len() != 4 {
         print_usage();
         eprintln!(
-            "{} Wrong number of arguments: expected 4, got {}.\n",
+            "{} Wrong number of arguments: expected 3 (with --in-place) or 4, got {}.\n",
             "Error:".red().bold(),
             args.len()
         );
@@ -75,53 +206,519 @@ fn parse_args() -> Arguments {
         target: args[0].clone(),
         replacement: args[1].clone(),
         filename: args[2].clone(),
-        output: args[3].clone(),
+        output: args.get(3).cloned().unwrap_or_default(),
         case_insensitive,
+        multiline,
+        dotall,
+        ignore_whitespace,
+        word,
+        literal,
+        no_expand,
+        in_place,
+        backup,
         interactive,
         preview,
         log_file,
+        log_format,
     }
 }
 
+/// Translate the C-style escape sequences `\n`, `\t`, `\r`, `\0` and `\\`
+/// in a replacement string into their byte values. Unknown escapes are
+/// left untouched so a stray backslash survives the round-trip.
+fn unescape(replacement: &str) -> String {
+    let mut out = String::with_capacity(replacement.len());
+    let mut chars = replacement.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => out.push('\n'),
+            Some('t') => out.push('\t'),
+            Some('r') => out.push('\r'),
+            Some('0') => out.push('\0'),
+            Some('\\') => out.push('\\'),
+            Some(other) => {
+                out.push('\\');
+                out.push(other);
+            }
+            None => out.push('\\'),
+        }
+    }
+    out
+}
+
+/// Convert a shell-style glob into an anchored regex: `\` and `.` are escaped,
+/// a single `*` becomes `[^/]*` and `?` becomes `[^/]` so neither crosses a
+/// path separator, and the whole thing is wrapped in `^...$` to match a path in
+/// full. A `**/` globstar run is consumed as a unit and emitted as `(?:.*/)?`
+/// so it spans zero or more directories — `src/**/*.txt` matches both
+/// `src/top.txt` and `src/a/b.txt`. A trailing `**` (no slash) becomes `.*`.
+fn glob_to_regex(glob: &str) -> String {
+    let mut out = String::from("^");
+    let mut chars = glob.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            '.' => out.push_str("\\."),
+            '*' => {
+                if chars.peek() == Some(&'*') {
+                    chars.next();
+                    if chars.peek() == Some(&'/') {
+                        chars.next();
+                        out.push_str("(?:.*/)?");
+                    } else {
+                        out.push_str(".*");
+                    }
+                } else {
+                    out.push_str("[^/]*");
+                }
+            }
+            '?' => out.push_str("[^/]"),
+            _ => out.push(c),
+        }
+    }
+    out.push('$');
+    out
+}
+
+/// Recursively collect the files beneath `root` so a glob can be matched
+/// against them. Directories that cannot be read are skipped silently.
+fn collect_files(root: &Path, out: &mut Vec<PathBuf>) {
+    let entries = match fs::read_dir(root) {
+        Ok(e) => e,
+        Err(_) => return,
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            collect_files(&path, out);
+        } else {
+            out.push(path);
+        }
+    }
+}
+
+/// Expand an `<INPUT>` argument into the list of files to process. A plain
+/// path is returned as-is; a pattern containing `*` or `?` is treated as a
+/// glob, walked from its non-wildcard prefix and filtered with
+/// [`glob_to_regex`].
+fn expand_inputs(pattern: &str) -> Vec<String> {
+    if !pattern.contains('*') && !pattern.contains('?') {
+        return vec![pattern.to_string()];
+    }
+
+    // Walk from the longest leading directory free of wildcards.
+    let prefix_end = pattern.find(['*', '?']).unwrap();
+    let root = match pattern[..prefix_end].rfind('/') {
+        Some(slash) => &pattern[..slash],
+        None => ".",
+    };
+    let root = if root.is_empty() { "/" } else { root };
+
+    let regex = match Regex::new(&glob_to_regex(pattern)) {
+        Ok(r) => r,
+        Err(_) => return Vec::new(),
+    };
+
+    let mut files = Vec::new();
+    collect_files(Path::new(root), &mut files);
+    let mut matched: Vec<String> = files
+        .iter()
+        .map(|p| p.to_string_lossy().trim_start_matches("./").to_string())
+        .filter(|p| regex.is_match(p))
+        .collect();
+    matched.sort();
+    matched
+}
+
+/// A single recorded replacement. Serialized directly for the JSON log
+/// format and rendered down to `Position: N, Matched: M` for the text one.
+#[derive(Debug, Serialize)]
+struct LogEntry {
+    position: usize,
+    line: usize,
+    column: usize,
+    matched: String,
+    replacement: String,
+    context: String,
+}
+
+/// How `replace` should build its pattern and emit replacements. Bundled into
+/// one struct so the behavior flags travel together instead of as a long tail
+/// of positional booleans.
+#[derive(Debug, Clone, Copy)]
+struct ReplaceOptions {
+    case_insensitive: bool,
+    multiline: bool,
+    dotall: bool,
+    ignore_whitespace: bool,
+    word: bool,
+    literal: bool,
+    no_expand: bool,
+}
+
 fn replace(
     target: &str,
     replacement: &str,
     text: &str,
-    case_insensitive: bool,
-) -> Result<(String, Vec<(usize, String)>), regex::Error> {
-    let regex = if case_insensitive {
-        Regex::new(&format!("(?i){}", target))?
+    opts: &ReplaceOptions,
+) -> Result<(String, Vec<LogEntry>), regex::Error> {
+    let mut pattern = if opts.literal {
+        regex::escape(target)
     } else {
-        Regex::new(target)?
+        target.to_string()
     };
+    // Whole-word mode anchors the (already-escaped, in literal mode) target
+    // between word boundaries.
+    if opts.word {
+        pattern = format!(r"\b(?:{})\b", pattern);
+    }
+    let regex = RegexBuilder::new(&pattern)
+        .case_insensitive(opts.case_insensitive)
+        .multi_line(opts.multiline)
+        .dot_matches_new_line(opts.dotall)
+        .ignore_whitespace(opts.ignore_whitespace)
+        .build()?;
+
+    // In literal mode the replacement is emitted verbatim; otherwise run the
+    // escape-sequence pass so `\n`, `\t`, … reach the output as real bytes.
+    let repl = if opts.literal {
+        replacement.to_string()
+    } else {
+        unescape(replacement)
+    };
+    // `$1`/`${name}` expansion is on by default in regex mode, but disabled
+    // for literal mode and whenever the caller opts out with `--no-expand`.
+    let expand = !opts.literal && !opts.no_expand;
 
     let mut log = Vec::new();
     let replaced = regex.replace_all(text, |caps: &regex::Captures| {
-        let match_text = caps.get(0).unwrap().as_str().to_string();
-        log.push((caps.get(0).unwrap().start(), match_text.clone()));
-        replacement.to_string()
+        let m = caps.get(0).unwrap();
+        let start = m.start();
+        let match_text = m.as_str().to_string();
+
+        let mut out = String::new();
+        if expand {
+            caps.expand(&repl, &mut out);
+        } else {
+            out.push_str(&repl);
+        }
+
+        // 1-based line/column from the byte offset, plus the line the match
+        // sits on for a bit of surrounding context in the log.
+        let line = text[..start].bytes().filter(|&b| b == b'\n').count() + 1;
+        let line_start = text[..start].rfind('\n').map(|i| i + 1).unwrap_or(0);
+        let line_end = text[start..]
+            .find('\n')
+            .map(|i| start + i)
+            .unwrap_or(text.len());
+        let column = start - line_start + 1;
+
+        log.push(LogEntry {
+            position: start,
+            line,
+            column,
+            matched: match_text,
+            replacement: out.clone(),
+            context: text[line_start..line_end].to_string(),
+        });
+        out
     });
 
     Ok((replaced.to_string(), log))
 }
 
-fn main() {
-    let args = parse_args();
+/// Batch path: expand `<INPUT>` into a file list and run [`replace`] over each
+/// one, writing either to a directory, to a single named file, or back in
+/// place (optionally preserving a `.bak` copy). Per-file change counts roll up
+/// into one aggregated statistics summary.
+fn run_batch(args: &Arguments) {
+    let files = expand_inputs(&args.filename);
+    if files.is_empty() {
+        eprintln!(
+            "{} No files matched '{}'\n",
+            "Error:".red().bold(),
+            args.filename
+        );
+        std::process::exit(1);
+    }
 
-    let data = match fs::read_to_string(&args.filename) {
-        Ok(v) => v,
-        Err(e) => {
+    let output_is_dir =
+        !args.in_place && !args.output.is_empty() && Path::new(&args.output).is_dir();
+
+    if !args.in_place && !output_is_dir && files.len() > 1 {
+        eprintln!(
+            "{} '{}' matched {} files; pass a directory or --in-place as <OUTPUT>.\n",
+            "Error:".red().bold(),
+            args.filename,
+            files.len()
+        );
+        std::process::exit(1);
+    }
+
+    let mut total_matches = 0usize;
+    let mut orig_lines = 0usize;
+    let mut orig_words = 0usize;
+    let mut mod_lines = 0usize;
+    let mut mod_words = 0usize;
+    let mut combined_log: Vec<(String, LogEntry)> = Vec::new();
+
+    for file in &files {
+        let data = match fs::read_to_string(file) {
+            Ok(v) => v,
+            Err(e) => {
+                eprintln!(
+                    "{} Failed to read from file '{}': {:?}\n",
+                    "Error:".red().bold(),
+                    file,
+                    e
+                );
+                std::process::exit(1);
+            }
+        };
+
+        let (replaced, log) = match replace(
+            &args.target,
+            &args.replacement,
+            &data,
+            &args.replace_options(),
+        ) {
+            Ok(v) => v,
+            Err(e) => {
+                eprintln!(
+                    "{} Failed to replace text: {:?}\n",
+                    "Error:".red().bold(),
+                    e
+                );
+                std::process::exit(1);
+            }
+        };
+
+        let dest: PathBuf = if args.in_place {
+            PathBuf::from(file)
+        } else if output_is_dir {
+            Path::new(&args.output).join(Path::new(file).file_name().unwrap_or_default())
+        } else {
+            PathBuf::from(&args.output)
+        };
+
+        // When editing in place, snapshot the original to `<file>.bak` first.
+        if args.in_place && args.backup {
+            let bak = format!("{}.bak", file);
+            if let Err(e) = fs::write(&bak, &data) {
+                eprintln!(
+                    "{} Failed to write backup '{}': {:?}\n",
+                    "Error:".red().bold(),
+                    bak,
+                    e
+                );
+                std::process::exit(1);
+            }
+        }
+
+        if let Err(e) = fs::write(&dest, &replaced) {
             eprintln!(
-                "{} Failed to read from file '{}': {:?}\n",
+                "{} Failed to write to file '{}': {:?}\n",
                 "Error:".red().bold(),
-                args.filename,
+                dest.display(),
                 e
             );
             std::process::exit(1);
         }
+        println!(
+            "{} Changes saved to '{}' ({} matches)\n",
+            "Success:".green().bold(),
+            dest.display(),
+            log.len()
+        );
+
+        total_matches += log.len();
+        orig_lines += data.lines().count();
+        orig_words += data.split_whitespace().count();
+        mod_lines += replaced.lines().count();
+        mod_words += replaced.split_whitespace().count();
+        for entry in log {
+            combined_log.push((file.clone(), entry));
+        }
+    }
+
+    // Statistics. A single-file run keeps the original singular summary; a
+    // multi-file batch adds the aggregated "files processed / total matches"
+    // header and labels the line/word totals as sums.
+    println!(
+        "{}\n{}\n{}",
+        "=====================".blue(),
+        "File Statistics:".yellow().bold(),
+        "=====================".blue()
+    );
+    if files.len() == 1 {
+        println!(
+            "{}\n  Lines: {}\n  Words: {}\n",
+            "Original File:".green(),
+            orig_lines,
+            orig_words
+        );
+        println!(
+            "{}\n  Lines: {}\n  Words: {}\n",
+            "Modified File:".green(),
+            mod_lines,
+            mod_words
+        );
+    } else {
+        println!(
+            "{}\n  Files processed: {}\n  Total matches: {}\n",
+            "Batch:".green(),
+            files.len(),
+            total_matches
+        );
+        println!(
+            "{}\n  Lines: {}\n  Words: {}\n",
+            "Original Files:".green(),
+            orig_lines,
+            orig_words
+        );
+        println!(
+            "{}\n  Lines: {}\n  Words: {}\n",
+            "Modified Files:".green(),
+            mod_lines,
+            mod_words
+        );
+    }
+
+    if let Some(log_file) = &args.log_file {
+        let log_data: String = if args.log_format == "json" {
+            let entries: Vec<BatchLogEntry> = combined_log
+                .iter()
+                .map(|(file, entry)| BatchLogEntry { file, entry })
+                .collect();
+            serde_json::to_string_pretty(&entries).unwrap_or_default()
+        } else {
+            combined_log
+                .iter()
+                .map(|(file, entry)| {
+                    format!("{}: Position: {}, Matched: {}", file, entry.position, entry.matched)
+                })
+                .collect::<Vec<_>>()
+                .join("\n")
+        };
+        match fs::write(log_file, log_data) {
+            Ok(_) => println!(
+                "{} Log saved to '{}'\n",
+                "Success:".green().bold(),
+                log_file
+            ),
+            Err(e) => eprintln!(
+                "{} Failed to write log to '{}': {:?}\n",
+                "Error:".red().bold(),
+                log_file,
+                e
+            ),
+        }
+    }
+}
+
+/// A [`LogEntry`] tagged with the file it came from, for the aggregated JSON
+/// log a batch run produces.
+#[derive(Serialize)]
+struct BatchLogEntry<'a> {
+    file: &'a str,
+    #[serde(flatten)]
+    entry: &'a LogEntry,
+}
+
+/// Produce a line-based unified diff of `original` vs `modified` as a list of
+/// `(marker, line)` pairs, where the marker is `' '` (unchanged), `'-'`
+/// (removed) or `'+'` (added). Uses a straightforward LCS so only the lines
+/// that actually differ are flagged.
+fn unified_diff(original: &str, modified: &str) -> Vec<(char, String)> {
+    let a: Vec<&str> = original.lines().collect();
+    let b: Vec<&str> = modified.lines().collect();
+    let (n, m) = (a.len(), b.len());
+
+    let mut dp = vec![vec![0usize; m + 1]; n + 1];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            dp[i][j] = if a[i] == b[j] {
+                dp[i + 1][j + 1] + 1
+            } else {
+                dp[i + 1][j].max(dp[i][j + 1])
+            };
+        }
+    }
+
+    let mut out = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < n && j < m {
+        if a[i] == b[j] {
+            out.push((' ', a[i].to_string()));
+            i += 1;
+            j += 1;
+        } else if dp[i + 1][j] >= dp[i][j + 1] {
+            out.push(('-', a[i].to_string()));
+            i += 1;
+        } else {
+            out.push(('+', b[j].to_string()));
+            j += 1;
+        }
+    }
+    while i < n {
+        out.push(('-', a[i].to_string()));
+        i += 1;
+    }
+    while j < m {
+        out.push(('+', b[j].to_string()));
+        j += 1;
+    }
+    out
+}
+
+fn main() {
+    let args = parse_args();
+
+    // The batch path handles globs, directory outputs and in-place edits. The
+    // single-stream path below stays responsible for stdin/stdout piping and
+    // the interactive/preview modes, which are inherently one buffer at a time.
+    if args.filename != "-"
+        && args.output != "-"
+        && !args.interactive
+        && !args.preview
+    {
+        run_batch(&args);
+        return;
+    }
+
+    let data = if args.filename == "-" {
+        let mut buf = String::new();
+        match io::stdin().read_to_string(&mut buf) {
+            Ok(_) => buf,
+            Err(e) => {
+                eprintln!(
+                    "{} Failed to read from stdin: {:?}\n",
+                    "Error:".red().bold(),
+                    e
+                );
+                std::process::exit(1);
+            }
+        }
+    } else {
+        match fs::read_to_string(&args.filename) {
+            Ok(v) => v,
+            Err(e) => {
+                eprintln!(
+                    "{} Failed to read from file '{}': {:?}\n",
+                    "Error:".red().bold(),
+                    args.filename,
+                    e
+                );
+                std::process::exit(1);
+            }
+        }
     };
 
-    let (replaced_data, log) = match replace(&args.target, &args.replacement, &data, args.case_insensitive) {
+    let (replaced_data, log) = match replace(&args.target, &args.replacement, &data, &args.replace_options()) {
         Ok(v) => v,
         Err(e) => {
             eprintln!(
@@ -133,7 +730,7 @@ fn main() {
         }
     };
 
-    // Preview Mode
+    // Preview Mode: render a colored unified diff and bail out without writing.
     if args.preview {
         println!(
             "{}\n{}\n{}",
@@ -141,16 +738,20 @@ fn main() {
             "Preview of Changes:".yellow().bold(),
             "=====================".blue()
         );
-        println!(
-            "{}\n\n{} {} changes found.\n",
-            replaced_data.lines().take(10).collect::<Vec<_>>().join("\n"),
-            "Preview Info:".blue().bold(),
-            log.len()
-        );
+        for (marker, line) in unified_diff(&data, &replaced_data) {
+            match marker {
+                '-' => println!("{}", format!("-{}", line).red()),
+                '+' => println!("{}", format!("+{}", line).green()),
+                _ => println!(" {}", line),
+            }
+        }
+        println!("\n{} {} changes found.\n", "Preview Info:".blue().bold(), log.len());
         return;
     }
 
-    // Interactive Mode
+    // Interactive Mode: walk the matches over the *original* text, prompting
+    // for each one, and rebuild the output by copying unmatched spans verbatim
+    // and only substituting the accepted matches.
     let mut final_data = String::new();
     if args.interactive {
         println!(
@@ -159,51 +760,116 @@ fn main() {
             "Interactive Replacement:".yellow().bold(),
             "=====================".blue()
         );
-        for line in replaced_data.lines() {
-            println!("{}\nReplace this line? [y/N]:", line.green());
-            let mut input = String::new();
-            io::stdin().read_line(&mut input).unwrap();
-            if input.trim().to_lowercase() == "y" {
-                final_data.push_str(line);
+        let mut cursor = 0;
+        let mut accept_all = false;
+        let mut quit = false;
+        for entry in &log {
+            let start = entry.position;
+            let end = start + entry.matched.len();
+            final_data.push_str(&data[cursor..start]);
+            cursor = end;
+
+            if quit {
+                final_data.push_str(&entry.matched);
+                continue;
+            }
+
+            let accepted = if accept_all {
+                true
+            } else {
+                println!(
+                    "{}:{}  {}",
+                    "Line".blue().bold(),
+                    entry.line,
+                    entry.context
+                );
+                println!("  {} {}", "-".red(), entry.matched.clone().red());
+                println!("  {} {}", "+".green(), entry.replacement.clone().green());
+                print!("Apply this change? [y/N/a/q]: ");
+                io::stdout().flush().ok();
+
+                let mut input = String::new();
+                io::stdin().read_line(&mut input).unwrap();
+                match input.trim().to_lowercase().as_str() {
+                    "y" => true,
+                    "a" => {
+                        accept_all = true;
+                        true
+                    }
+                    "q" => {
+                        quit = true;
+                        false
+                    }
+                    _ => false,
+                }
+            };
+
+            if accepted {
+                final_data.push_str(&entry.replacement);
+            } else {
+                final_data.push_str(&entry.matched);
             }
-            final_data.push('\n');
         }
+        final_data.push_str(&data[cursor..]);
     } else {
         final_data = replaced_data.clone();
     }
 
-    // Save changes
-    match fs::write(&args.output, &final_data) {
-        Ok(_) => println!(
-            "{} Changes saved to '{}'\n",
-            "Success:".green().bold(),
-            args.output
-        ),
-        Err(e) => {
+    // Save changes. A `-` output streams to stdout so the tool can sit in a
+    // pipeline; in that mode we stay silent on success and push every status
+    // line to stderr so it never corrupts the piped data.
+    let to_stdout = args.output == "-";
+    if to_stdout {
+        if let Err(e) = io::stdout().write_all(final_data.as_bytes()) {
             eprintln!(
-                "{} Failed to write to file '{}': {:?}\n",
+                "{} Failed to write to stdout: {:?}\n",
                 "Error:".red().bold(),
-                args.output,
                 e
             );
             std::process::exit(1);
         }
+    } else {
+        match fs::write(&args.output, &final_data) {
+            Ok(_) => println!(
+                "{} Changes saved to '{}'\n",
+                "Success:".green().bold(),
+                args.output
+            ),
+            Err(e) => {
+                eprintln!(
+                    "{} Failed to write to file '{}': {:?}\n",
+                    "Error:".red().bold(),
+                    args.output,
+                    e
+                );
+                std::process::exit(1);
+            }
+        }
     }
 
     // Line and Word Count
-    println!(
+    macro_rules! stat {
+        ($($arg:tt)*) => {
+            if to_stdout {
+                eprintln!($($arg)*);
+            } else {
+                println!($($arg)*);
+            }
+        };
+    }
+    stat!(
         "{}\n{}\n{}",
         "=====================".blue(),
         "File Statistics:".yellow().bold(),
         "=====================".blue()
     );
-    println!(
+    stat!(
         "{}\n  Lines: {}\n  Words: {}\n",
         "Original File:".green(),
         data.lines().count(),
         data.split_whitespace().count()
     );
-    println!(
+    stat!(
         "{}\n  Lines: {}\n  Words: {}\n",
         "Modified File:".green(),
         final_data.lines().count(),
@@ -212,13 +878,16 @@ fn main() {
 
     // Log File
     if let Some(log_file) = args.log_file {
-        let log_data: String = log
-            .iter()
-            .map(|(pos, matched)| format!("Position: {}, Matched: {}", pos, matched))
-            .collect::<Vec<_>>()
-            .join("\n");
+        let log_data: String = if args.log_format == "json" {
+            serde_json::to_string_pretty(&log).unwrap_or_default()
+        } else {
+            log.iter()
+                .map(|e| format!("Position: {}, Matched: {}", e.position, e.matched))
+                .collect::<Vec<_>>()
+                .join("\n")
+        };
         match fs::write(&log_file, log_data) {
-            Ok(_) => println!(
+            Ok(_) => stat!(
                 "{} Log saved to '{}'\n",
                 "Success:".green().bold(),
                 log_file